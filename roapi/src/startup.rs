@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use columnq::table::TableSource;
 use log::{error, info};
+use rand::Rng;
 use snafu::prelude::*;
 use tokio::sync::{Mutex, RwLock};
 use tokio::time;
@@ -21,25 +22,105 @@ pub enum Error {
     BuildFlightSqlServer { source: server::flight_sql::Error },
 }
 
+/// Tick granularity for the reloader's wakeup loop. Per-table `next_fire`
+/// deadlines are checked on every tick rather than driving one timer per
+/// table.
+const RELOADER_TICK: Duration = Duration::from_millis(500);
+
+/// Base of the exponential backoff. The failure count is at least 1 when
+/// `backoff_with_jitter` runs, so the first retry waits twice this delay.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Upper bound on the exponential backoff delay between retries, before the
+/// jitter in `backoff_with_jitter` is added on top.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Computes `base * 2^failures` capped at `RETRY_MAX_DELAY`, plus up to 25%
+/// jitter, shared by both the per-table reloader and the concurrent refresh
+/// loop so a flaky source backs off the same way on either path.
+fn backoff_with_jitter(failures: u32) -> Duration {
+    let backoff = RETRY_BASE_DELAY
+        .saturating_mul(1u32.checked_shl(failures).unwrap_or(u32::MAX))
+        .min(RETRY_MAX_DELAY);
+    let jitter_ms = rand::thread_rng().gen_range(0..=backoff.as_millis() as u64 / 4 + 1);
+    backoff + Duration::from_millis(jitter_ms)
+}
+
+/// Per-table bookkeeping for the reloader's backoff schedule.
+struct TableReloadState {
+    interval: Duration,
+    next_fire: Instant,
+    failures: u32,
+}
+
+impl TableReloadState {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next_fire: Instant::now(),
+            failures: 0,
+        }
+    }
+
+    fn on_success(&mut self) {
+        self.failures = 0;
+        self.next_fire = Instant::now() + self.interval;
+    }
+
+    fn on_failure(&mut self) {
+        self.failures = self.failures.saturating_add(1);
+        self.next_fire = Instant::now() + backoff_with_jitter(self.failures);
+    }
+}
+
 // TODO: replace table reloader with the new concurrent refresh infra
+//
+// TODO: support a per-table reload interval override. That needs a new field
+// on `columnq::table::TableSource`, which isn't part of this checkout, so for
+// now every table reloads on `default_interval` and only the retry/backoff
+// half of the per-table behavior below is implemented.
 pub struct TableReloader {
-    reload_interval: Duration,
+    default_interval: Duration,
     ctx_ext: Arc<RwLock<RawRoapiContext>>,
     tables: Arc<Mutex<HashMap<String, TableSource>>>,
+    reload_states: Mutex<HashMap<String, TableReloadState>>,
 }
 
 impl TableReloader {
     pub async fn run(self) {
-        let mut interval = time::interval(self.reload_interval);
+        let mut interval = time::interval(RELOADER_TICK);
         loop {
             interval.tick().await;
-            for (table_name, table) in self.tables.lock().await.iter() {
+            let now = Instant::now();
+
+            let mut reload_states = self.reload_states.lock().await;
+            let tables = self.tables.lock().await;
+            // Drop bookkeeping for tables that were unregistered since the last
+            // tick so `reload_states` doesn't grow unbounded under table churn.
+            reload_states.retain(|table_name, _| tables.contains_key(table_name));
+
+            for (table_name, table) in tables.iter() {
+                let state = reload_states
+                    .entry(table_name.clone())
+                    .or_insert_with(|| TableReloadState::new(self.default_interval));
+                if state.next_fire > now {
+                    continue;
+                }
+
                 match self.ctx_ext.load_table(table).await {
                     Ok(_) => {
                         info!("table {} reloaded", table_name);
+                        state.on_success();
                     }
                     Err(err) => {
-                        error!("failed to reload table {}: {:?}", table_name, err);
+                        state.on_failure();
+                        error!(
+                            "failed to reload table {} (attempt {}), retrying in {:?}: {:?}",
+                            table_name,
+                            state.failures,
+                            state.next_fire.saturating_duration_since(now),
+                            err
+                        );
                     }
                 }
             }
@@ -82,9 +163,10 @@ impl Application {
             );
 
             let table_reloader = config.reload_interval.map(|reload_interval| TableReloader {
-                reload_interval,
+                default_interval: reload_interval,
                 tables: tables.clone(),
                 ctx_ext: ctx_ext.clone(),
+                reload_states: Mutex::new(HashMap::new()),
             });
 
             let flight_sql_server = Box::new(
@@ -102,12 +184,29 @@ impl Application {
                     .await
                     .context(BuildHttpServerSnafu)?;
 
+            // `refresh_tables` reloads every table in a single call, so a failure
+            // can't be attributed to one table the way `TableReloader` does; this
+            // backs off the whole cycle instead of retrying in lockstep every second.
             let _handle = tokio::task::spawn(async move {
+                const REFRESH_INTERVAL: Duration = Duration::from_millis(1000);
+                let mut failures: u32 = 0;
                 loop {
-                    if let Err(e) = ctx_ext.refresh_tables().await {
-                        error!("Failed to refresh table: {:?}", e);
-                    }
-                    time::sleep(Duration::from_millis(1000)).await;
+                    let sleep_for = match ctx_ext.refresh_tables().await {
+                        Ok(_) => {
+                            failures = 0;
+                            REFRESH_INTERVAL
+                        }
+                        Err(e) => {
+                            failures = failures.saturating_add(1);
+                            let delay = backoff_with_jitter(failures);
+                            error!(
+                                "Failed to refresh table (attempt {}), retrying in {:?}: {:?}",
+                                failures, delay, e
+                            );
+                            delay
+                        }
+                    };
+                    time::sleep(sleep_for).await;
                 }
             });
 